@@ -0,0 +1,178 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use num_traits::Float;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::{Operation, Value};
+
+#[derive(Serialize, Deserialize)]
+struct NodeRecord<T> {
+    id: usize,
+    data: T,
+    grad: T,
+    op: Option<OpRecord>,
+}
+
+#[derive(Serialize, Deserialize)]
+enum OpRecord {
+    Addition { lhs: usize, rhs: usize },
+    Subtraction { lhs: usize, rhs: usize },
+    Multiplication { lhs: usize, rhs: usize },
+    Exponentiation { base: usize, pow: u32 },
+}
+
+impl<T: Float + Serialize> Value<T> {
+    /// Serializes this value and its entire upstream computation graph to
+    /// JSON. Nodes reused multiple times in the graph (per `Rc` identity)
+    /// are emitted once and referenced by id from their dependents.
+    pub fn to_graph_json(&self) -> String {
+        let mut ids = HashMap::new();
+        let mut records = Vec::new();
+
+        // `toposort` orders the root first; reverse it so dependencies are
+        // emitted (and thus resolvable) before the nodes that reference them.
+        for value in self.toposort().into_iter().rev() {
+            let id = value.ptr_id();
+            if ids.contains_key(&id) {
+                continue;
+            }
+            let next_id = ids.len();
+            ids.insert(id, next_id);
+
+            let op = value.operation().map(|op| match op.as_ref() {
+                Operation::Addition(lhs, rhs) => OpRecord::Addition {
+                    lhs: ids[&lhs.ptr_id()],
+                    rhs: ids[&rhs.ptr_id()],
+                },
+                Operation::Subtraction(lhs, rhs) => OpRecord::Subtraction {
+                    lhs: ids[&lhs.ptr_id()],
+                    rhs: ids[&rhs.ptr_id()],
+                },
+                Operation::Multiplication(lhs, rhs) => OpRecord::Multiplication {
+                    lhs: ids[&lhs.ptr_id()],
+                    rhs: ids[&rhs.ptr_id()],
+                },
+                Operation::Exponentiation(base, pow) => OpRecord::Exponentiation {
+                    base: ids[&base.ptr_id()],
+                    pow: *pow,
+                },
+            });
+
+            records.push(NodeRecord {
+                id: next_id,
+                data: value.data(),
+                grad: value.grad(),
+                op,
+            });
+        }
+
+        serde_json::to_string(&records).expect("graph serialization cannot fail")
+    }
+}
+
+impl<T: Float + DeserializeOwned> Value<T> {
+    /// Reconstructs a `Value` (and its full upstream DAG) previously
+    /// produced by [`Value::to_graph_json`]. Operand ids are resolved back
+    /// into shared `Rc` handles so a node referenced by multiple dependents
+    /// is rebuilt once, mirroring the sharing invariant of the live graph
+    /// (see `test_value_reuse`).
+    pub fn from_graph_json(json: &str) -> Result<Value<T>, serde_json::Error> {
+        let records: Vec<NodeRecord<T>> = serde_json::from_str(json)?;
+
+        let mut nodes: Vec<Value<T>> = Vec::with_capacity(records.len());
+        for record in records {
+            if record.id != nodes.len() {
+                return Err(serde::de::Error::custom(format!(
+                    "node id {} is out of sequence: expected {}",
+                    record.id,
+                    nodes.len()
+                )));
+            }
+
+            let operand = |id: usize| -> Result<Value<T>, serde_json::Error> {
+                nodes.get(id).cloned().ok_or_else(|| {
+                    serde::de::Error::custom(format!(
+                        "operand id {id} does not refer to an already-built node"
+                    ))
+                })
+            };
+
+            let operation = match record.op {
+                Some(OpRecord::Addition { lhs, rhs }) => {
+                    Some(Operation::Addition(operand(lhs)?, operand(rhs)?))
+                }
+                Some(OpRecord::Subtraction { lhs, rhs }) => {
+                    Some(Operation::Subtraction(operand(lhs)?, operand(rhs)?))
+                }
+                Some(OpRecord::Multiplication { lhs, rhs }) => {
+                    Some(Operation::Multiplication(operand(lhs)?, operand(rhs)?))
+                }
+                Some(OpRecord::Exponentiation { base, pow }) => {
+                    Some(Operation::Exponentiation(operand(base)?, pow))
+                }
+                None => None,
+            }
+            .map(Rc::new);
+
+            let value = Value::new(record.data, operation);
+            value.set_grad(record.grad);
+            nodes.push(value);
+        }
+
+        nodes
+            .pop()
+            .ok_or_else(|| serde::de::Error::custom("empty computation graph"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ValueF64;
+
+    #[test]
+    fn test_roundtrip_preserves_shared_subgraph() {
+        let a = ValueF64::from_val(1.0);
+        let b = a.clone() + a.clone();
+        b.backward();
+
+        let json = b.to_graph_json();
+        let restored = ValueF64::from_graph_json(&json).unwrap();
+
+        assert_eq!(restored.data(), b.data());
+        assert_eq!(restored.grad(), b.grad());
+        // Both operands of the restored addition must be the same node.
+        match restored.operation().map(|op| op.as_ref()) {
+            Some(crate::Operation::Addition(lhs, rhs)) => assert_eq!(lhs, rhs),
+            _ => panic!("restored root is not an addition"),
+        }
+    }
+
+    #[test]
+    fn test_roundtrip_then_backward_matches_original() {
+        let x = ValueF64::from_val(3.0);
+        let y = ValueF64::from_val(4.0) * x.clone().powi(5);
+
+        let restored = ValueF64::from_graph_json(&y.to_graph_json()).unwrap();
+        restored.backward();
+        y.backward();
+
+        assert_eq!(restored.data(), y.data());
+        assert_eq!(restored.grad(), y.grad());
+    }
+
+    #[test]
+    fn test_out_of_range_operand_id_is_an_error_not_a_panic() {
+        let json = r#"[{"id":0,"data":1.0,"grad":0.0,"op":{"Addition":{"lhs":5,"rhs":0}}}]"#;
+
+        assert!(ValueF64::from_graph_json(json).is_err());
+    }
+
+    #[test]
+    fn test_record_id_out_of_sequence_is_an_error() {
+        let json = r#"[{"id":1,"data":1.0,"grad":0.0,"op":null}]"#;
+
+        assert!(ValueF64::from_graph_json(json).is_err());
+    }
+}
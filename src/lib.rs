@@ -5,22 +5,31 @@ use std::hash::{Hash, Hasher};
 use std::ops::{Mul, Sub};
 use std::rc::Rc;
 
+use num_traits::Float;
+
+mod graph_serde;
+mod matrix;
+mod tape;
+
+pub use matrix::Matrix;
+pub use tape::{Tape, TapeValue};
+
 #[derive(Clone, Copy, Debug)]
-struct ValueData {
-    data: f64,
-    grad: f64,
+struct ValueData<T> {
+    data: T,
+    grad: T,
 }
 
 #[derive(Debug, Clone)]
-enum Operation {
-    Addition(Value, Value),
-    Subtraction(Value, Value),
-    Multiplication(Value, Value),
-    Exponentiation(Value, u32),
+pub(crate) enum Operation<T> {
+    Addition(Value<T>, Value<T>),
+    Subtraction(Value<T>, Value<T>),
+    Multiplication(Value<T>, Value<T>),
+    Exponentiation(Value<T>, u32),
 }
 
-impl Operation {
-    fn calculate_gradients(&self, grad: f64) {
+impl<T: Float> Operation<T> {
+    fn calculate_gradients(&self, grad: T) {
         match self {
             Operation::Addition(lhs, rhs) => {
                 lhs.set_grad(lhs.grad() + grad);
@@ -31,14 +40,14 @@ impl Operation {
                 rhs.set_grad(rhs.grad() - grad);
             }
             Operation::Multiplication(lhs, rhs) => {
-                lhs.set_grad(grad * rhs.data());
-                rhs.set_grad(grad * lhs.data());
+                lhs.set_grad(lhs.grad() + grad * rhs.data());
+                rhs.set_grad(rhs.grad() + grad * lhs.data());
             }
             Operation::Exponentiation(base, pow) => {
                 if *pow > 0 {
-                    base.set_grad(grad * (*pow as f64) * base.data().powi(*pow as i32 - 1));
-                } else {
-                    base.set_grad(0.0);
+                    base.set_grad(
+                        base.grad() + grad * T::from(*pow).unwrap() * base.data().powi(*pow as i32 - 1),
+                    );
                 }
             }
         }
@@ -46,45 +55,62 @@ impl Operation {
 }
 
 #[derive(Debug, Clone)]
-pub struct Value {
-    data: Rc<RefCell<ValueData>>,
-    operation: Option<Rc<Operation>>,
+pub struct Value<T> {
+    data: Rc<RefCell<ValueData<T>>>,
+    operation: Option<Rc<Operation<T>>>,
 }
 
-impl Value {
-    pub fn from_val(val: f64) -> Self {
+/// Convenience alias for the common case of autodiff over `f64`, matching
+/// the type this crate used before it became generic over `T: Float`.
+pub type ValueF64 = Value<f64>;
+
+impl<T: Float> Value<T> {
+    pub fn from_val(val: T) -> Self {
         Self::new(val, None)
     }
 
-    fn new(data: f64, operation: Option<Rc<Operation>>) -> Self {
+    pub(crate) fn new(data: T, operation: Option<Rc<Operation<T>>>) -> Self {
         Self {
-            data: Rc::new(RefCell::new(ValueData { data, grad: 0.0 })),
+            data: Rc::new(RefCell::new(ValueData {
+                data,
+                grad: T::zero(),
+            })),
             operation,
         }
     }
 
-    pub fn data(&self) -> f64 {
+    pub fn data(&self) -> T {
         self.data.borrow().data
     }
 
-    pub fn grad(&self) -> f64 {
+    pub fn grad(&self) -> T {
         self.data.borrow().grad
     }
 
-    pub fn powi(self, exp: u32) -> Value {
+    /// Stable identity for this node's underlying `Rc`, used by graph
+    /// serialization to detect when a `Value` is shared across the DAG.
+    pub(crate) fn ptr_id(&self) -> usize {
+        Rc::as_ptr(&self.data) as usize
+    }
+
+    pub(crate) fn operation(&self) -> Option<&Rc<Operation<T>>> {
+        self.operation.as_ref()
+    }
+
+    pub fn powi(self, exp: u32) -> Value<T> {
         Value::new(
             self.data().powi(exp as i32),
             Some(Rc::new(Operation::Exponentiation(self, exp))),
         )
     }
 
-    pub fn set_grad(&self, grad: f64) {
+    pub fn set_grad(&self, grad: T) {
         let self_grad = &mut RefCell::borrow_mut(&self.data).grad;
         *self_grad = grad;
     }
 
     pub fn backward(&self) {
-        self.set_grad(1.0);
+        self.set_grad(T::one());
         for value in self.toposort() {
             if let Some(operation) = &value.operation {
                 operation.calculate_gradients(value.grad());
@@ -92,7 +118,11 @@ impl Value {
         }
     }
 
-    fn toposort(&self) -> Vec<&Value> {
+    // `Value`'s `Hash`/`Eq` are based on `Rc` pointer identity, not on the
+    // interior-mutable `grad`/`data` fields, so bucket placement in this set
+    // never changes as gradients accumulate during traversal.
+    #[allow(clippy::mutable_key_type)]
+    pub(crate) fn toposort(&self) -> Vec<&Value<T>> {
         let mut ordering = vec![];
         let mut visited = HashSet::new();
         self.toposort_impl(&mut visited, &mut ordering);
@@ -100,10 +130,11 @@ impl Value {
         ordering
     }
 
+    #[allow(clippy::mutable_key_type)]
     fn toposort_impl<'a>(
         &'a self,
-        visited: &mut HashSet<&'a Value>,
-        traversal: &mut Vec<&'a Value>,
+        visited: &mut HashSet<&'a Value<T>>,
+        traversal: &mut Vec<&'a Value<T>>,
     ) {
         if visited.contains(&self) {
             return;
@@ -133,24 +164,26 @@ impl Value {
     }
 }
 
-impl PartialEq for Value {
-    fn eq(&self, other: &Value) -> bool {
+// `PartialEq`/`Eq`/`Hash` are based on `Rc` pointer identity rather than on
+// `T`, since `T` need not itself be `Eq`/`Hash` (e.g. `f32`/`f64`).
+impl<T> PartialEq for Value<T> {
+    fn eq(&self, other: &Value<T>) -> bool {
         Rc::ptr_eq(&self.data, &other.data)
     }
 }
 
-impl Eq for Value {}
+impl<T> Eq for Value<T> {}
 
-impl Hash for Value {
+impl<T> Hash for Value<T> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         Rc::as_ptr(&self.data).hash(state);
     }
 }
 
-impl std::ops::Add for Value {
-    type Output = Value;
+impl<T: Float> std::ops::Add for Value<T> {
+    type Output = Value<T>;
 
-    fn add(self, other: Value) -> Value {
+    fn add(self, other: Value<T>) -> Value<T> {
         Value::new(
             self.data() + other.data(),
             Some(Rc::new(Operation::Addition(self, other))),
@@ -158,10 +191,10 @@ impl std::ops::Add for Value {
     }
 }
 
-impl Sub for Value {
-    type Output = Value;
+impl<T: Float> Sub for Value<T> {
+    type Output = Value<T>;
 
-    fn sub(self, other: Value) -> Value {
+    fn sub(self, other: Value<T>) -> Value<T> {
         Value::new(
             self.data() - other.data(),
             Some(Rc::new(Operation::Subtraction(self, other))),
@@ -169,10 +202,10 @@ impl Sub for Value {
     }
 }
 
-impl Mul for Value {
-    type Output = Value;
+impl<T: Float> Mul for Value<T> {
+    type Output = Value<T>;
 
-    fn mul(self, other: Value) -> Value {
+    fn mul(self, other: Value<T>) -> Value<T> {
         Value::new(
             self.data() * other.data(),
             Some(Rc::new(Operation::Multiplication(self, other))),
@@ -186,7 +219,7 @@ mod test {
 
     #[test]
     fn test_value_reuse() {
-        let a = Value::from_val(1.0);
+        let a = ValueF64::from_val(1.0);
         let b = a.clone() + a.clone();
         b.backward();
 
@@ -196,8 +229,8 @@ mod test {
 
     #[test]
     fn test_add() {
-        let a = Value::from_val(1.0);
-        let b = Value::from_val(2.0);
+        let a = ValueF64::from_val(1.0);
+        let b = ValueF64::from_val(2.0);
         let c = a.clone() + b.clone();
         c.backward();
 
@@ -209,8 +242,8 @@ mod test {
 
     #[test]
     fn test_sub() {
-        let a = Value::from_val(1.0);
-        let b = Value::from_val(2.0);
+        let a = ValueF64::from_val(1.0);
+        let b = ValueF64::from_val(2.0);
         let c = a.clone() - b.clone();
         c.backward();
 
@@ -222,8 +255,8 @@ mod test {
 
     #[test]
     fn test_mul() {
-        let a = Value::from_val(11.0);
-        let b = Value::from_val(12.0);
+        let a = ValueF64::from_val(11.0);
+        let b = ValueF64::from_val(12.0);
         let c = a.clone() * b.clone();
         c.backward();
 
@@ -235,9 +268,9 @@ mod test {
 
     #[test]
     fn test_powi_positive_exp() {
-        let x = Value::from_val(3.0);
+        let x = ValueF64::from_val(3.0);
         // y = 4 * x^5
-        let y = Value::from_val(4.0) * x.clone().powi(5);
+        let y = ValueF64::from_val(4.0) * x.clone().powi(5);
         y.backward();
 
         assert_eq!(y.data(), 972.0);
@@ -248,7 +281,7 @@ mod test {
 
     #[test]
     fn test_powi_zero_exp() {
-        let x = Value::from_val(3.0);
+        let x = ValueF64::from_val(3.0);
         // y = x^0
         let y = x.clone().powi(0);
         y.backward();
@@ -258,4 +291,16 @@ mod test {
         // dy/dx = 20x^4
         assert_eq!(x.grad(), 0.0);
     }
+
+    #[test]
+    fn test_generic_f32() {
+        let a = Value::<f32>::from_val(1.0_f32);
+        let b = Value::<f32>::from_val(2.0_f32);
+        let c = a.clone() * b.clone();
+        c.backward();
+
+        assert_eq!(c.data(), 2.0_f32);
+        assert_eq!(a.grad(), 2.0_f32);
+        assert_eq!(b.grad(), 1.0_f32);
+    }
 }
@@ -0,0 +1,166 @@
+use num_traits::Float;
+
+use crate::Value;
+
+/// A dense, row-major matrix of autodiff [`Value`]s.
+///
+/// `Matrix` is built entirely out of the existing `Value` ops (`+`, `*`), so
+/// gradients flow through `matmul`, `add`, and `sum` the same way they do for
+/// scalar expressions — call `.backward()` on the `Value` returned by `sum()`
+/// to differentiate the whole pipeline.
+#[derive(Debug, Clone)]
+pub struct Matrix<T> {
+    data: Vec<Value<T>>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T: Float> Matrix<T> {
+    pub fn new(rows: usize, cols: usize, data: Vec<Value<T>>) -> Self {
+        assert_eq!(
+            data.len(),
+            rows * cols,
+            "matrix data length must equal rows * cols"
+        );
+        Self { data, rows, cols }
+    }
+
+    pub fn from_vals(rows: usize, cols: usize, vals: Vec<T>) -> Self {
+        Self::new(rows, cols, vals.into_iter().map(Value::from_val).collect())
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> &Value<T> {
+        &self.data[row * self.cols + col]
+    }
+
+    pub fn matmul(&self, other: &Matrix<T>) -> Matrix<T> {
+        assert_eq!(
+            self.cols, other.rows,
+            "matmul dimension mismatch: {}x{} * {}x{}",
+            self.rows, self.cols, other.rows, other.cols
+        );
+
+        let mut data = Vec::with_capacity(self.rows * other.cols);
+        for i in 0..self.rows {
+            for j in 0..other.cols {
+                let mut sum = Value::from_val(T::zero());
+                for p in 0..self.cols {
+                    sum = sum + self.get(i, p).clone() * other.get(p, j).clone();
+                }
+                data.push(sum);
+            }
+        }
+
+        Matrix::new(self.rows, other.cols, data)
+    }
+
+    pub fn add(&self, other: &Matrix<T>) -> Matrix<T> {
+        assert_eq!(
+            (self.rows, self.cols),
+            (other.rows, other.cols),
+            "elementwise add requires matching dimensions"
+        );
+
+        let data = self
+            .data
+            .iter()
+            .zip(other.data.iter())
+            .map(|(a, b)| a.clone() + b.clone())
+            .collect();
+
+        Matrix::new(self.rows, self.cols, data)
+    }
+
+    pub fn transpose(&self) -> Matrix<T> {
+        let mut data = Vec::with_capacity(self.data.len());
+        for j in 0..self.cols {
+            for i in 0..self.rows {
+                data.push(self.get(i, j).clone());
+            }
+        }
+        Matrix::new(self.cols, self.rows, data)
+    }
+
+    pub fn map(&self, f: impl Fn(Value<T>) -> Value<T>) -> Matrix<T> {
+        let data = self.data.iter().cloned().map(f).collect();
+        Matrix::new(self.rows, self.cols, data)
+    }
+
+    /// Reduces the matrix to a single scalar `Value` by summing all entries.
+    pub fn sum(&self) -> Value<T> {
+        let mut total = Value::from_val(T::zero());
+        for value in &self.data {
+            total = total + value.clone();
+        }
+        total
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_matmul_forward() {
+        let a = Matrix::from_vals(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+        let b = Matrix::from_vals(2, 2, vec![5.0, 6.0, 7.0, 8.0]);
+        let c = a.matmul(&b);
+
+        assert_eq!(c.get(0, 0).data(), 19.0);
+        assert_eq!(c.get(0, 1).data(), 22.0);
+        assert_eq!(c.get(1, 0).data(), 43.0);
+        assert_eq!(c.get(1, 1).data(), 50.0);
+    }
+
+    #[test]
+    fn test_matmul_backward_accumulates_shared_grad() {
+        // x @ x with x reused on both sides exercises the accumulating-grad
+        // fix in `Operation::Multiplication`.
+        let x = Matrix::from_vals(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+        let y = x.matmul(&x);
+        let loss = y.sum();
+        loss.backward();
+
+        // y00 = x00*x00 + x01*x10 -> d/dx00 = 2*x00 = 2
+        // y01 = x00*x01 + x01*x11 -> d/dx00 = x01 = 2
+        // y10 = x10*x00 + x11*x10 -> d/dx00 = x10 = 3
+        // y11 = x10*x01 + x11*x11 -> d/dx00 = 0
+        assert_eq!(x.get(0, 0).grad(), 7.0);
+    }
+
+    #[test]
+    fn test_elementwise_add_and_sum() {
+        let a = Matrix::from_vals(1, 3, vec![1.0, 2.0, 3.0]);
+        let b = Matrix::from_vals(1, 3, vec![4.0, 5.0, 6.0]);
+        let c = a.add(&b);
+
+        assert_eq!(c.sum().data(), 21.0);
+    }
+
+    #[test]
+    fn test_transpose() {
+        let a = Matrix::from_vals(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let t = a.transpose();
+
+        assert_eq!(t.rows(), 3);
+        assert_eq!(t.cols(), 2);
+        assert_eq!(t.get(2, 1).data(), 6.0);
+    }
+
+    #[test]
+    fn test_map_relu() {
+        let a = Matrix::from_vals(1, 3, vec![-1.0, 0.0, 2.0]);
+        let relu = a.map(|v| if v.data() > 0.0 { v } else { Value::from_val(0.0) });
+
+        assert_eq!(relu.get(0, 0).data(), 0.0);
+        assert_eq!(relu.get(0, 2).data(), 2.0);
+    }
+}
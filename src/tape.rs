@@ -0,0 +1,296 @@
+use std::cell::RefCell;
+use std::ops::{Mul, Sub};
+use std::rc::Rc;
+
+use num_traits::Float;
+
+/// An arena-backed, iterative alternative to [`crate::Value`].
+///
+/// `Value` allocates one `Rc<RefCell<_>>` per node and walks the graph with
+/// a recursive toposort, so a long chain (an RNN unrolled over many steps,
+/// a deep polynomial) can blow the stack and pays one heap allocation per
+/// op. A `Tape` instead owns a flat `Vec` of nodes; `TapeValue`s are just
+/// `(tape, idx)` pairs, and both the topological order and `backward` are
+/// computed with an explicit work stack, so traversal never recurses and
+/// stays cache-friendly even for graphs with hundreds of thousands of ops.
+#[derive(Debug, Default)]
+pub struct Tape<T> {
+    nodes: Vec<TapeNode<T>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TapeNode<T> {
+    data: T,
+    grad: T,
+    op: Option<TapeOp>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum TapeOp {
+    Addition(usize, usize),
+    Subtraction(usize, usize),
+    Multiplication(usize, usize),
+    Exponentiation(usize, u32),
+}
+
+impl<T: Float> Tape<T> {
+    pub fn new() -> Rc<RefCell<Tape<T>>> {
+        Rc::new(RefCell::new(Tape { nodes: Vec::new() }))
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    fn push(&mut self, data: T, op: Option<TapeOp>) -> usize {
+        let idx = self.nodes.len();
+        self.nodes.push(TapeNode {
+            data,
+            grad: T::zero(),
+            op,
+        });
+        idx
+    }
+}
+
+/// A node in a [`Tape`], identified by its index rather than its own `Rc`.
+#[derive(Debug, Clone)]
+pub struct TapeValue<T> {
+    tape: Rc<RefCell<Tape<T>>>,
+    idx: usize,
+}
+
+impl<T: Float> TapeValue<T> {
+    pub fn from_val(tape: &Rc<RefCell<Tape<T>>>, val: T) -> Self {
+        let idx = tape.borrow_mut().push(val, None);
+        TapeValue {
+            tape: tape.clone(),
+            idx,
+        }
+    }
+
+    pub fn data(&self) -> T {
+        self.tape.borrow().nodes[self.idx].data
+    }
+
+    pub fn grad(&self) -> T {
+        self.tape.borrow().nodes[self.idx].grad
+    }
+
+    pub fn set_grad(&self, grad: T) {
+        self.tape.borrow_mut().nodes[self.idx].grad = grad;
+    }
+
+    pub fn powi(self, exp: u32) -> TapeValue<T> {
+        let data = self.data().powi(exp as i32);
+        let idx = self
+            .tape
+            .borrow_mut()
+            .push(data, Some(TapeOp::Exponentiation(self.idx, exp)));
+        TapeValue {
+            tape: self.tape,
+            idx,
+        }
+    }
+
+    pub fn backward(&self) {
+        self.set_grad(T::one());
+
+        // Leaves-first order from an iterative post-order DFS: when a node is
+        // first popped, mark it visited, push a sentinel copy of it, then push
+        // its children on top; the sentinel is recorded when it is popped
+        // (its children are guaranteed to have been recorded by then).
+        let order = {
+            let tape = self.tape.borrow();
+            let mut visited = vec![false; tape.nodes.len()];
+            let mut order = Vec::with_capacity(tape.nodes.len());
+            let mut stack = vec![(self.idx, false)];
+            while let Some((idx, expanded)) = stack.pop() {
+                if expanded {
+                    order.push(idx);
+                    continue;
+                }
+                if visited[idx] {
+                    continue;
+                }
+                visited[idx] = true;
+                stack.push((idx, true));
+                if let Some(op) = tape.nodes[idx].op {
+                    match op {
+                        TapeOp::Addition(lhs, rhs)
+                        | TapeOp::Subtraction(lhs, rhs)
+                        | TapeOp::Multiplication(lhs, rhs) => {
+                            stack.push((rhs, false));
+                            stack.push((lhs, false));
+                        }
+                        TapeOp::Exponentiation(base, _) => stack.push((base, false)),
+                    }
+                }
+            }
+            order
+        };
+
+        for idx in order.into_iter().rev() {
+            let (op, grad) = {
+                let tape = self.tape.borrow();
+                (tape.nodes[idx].op, tape.nodes[idx].grad)
+            };
+            let Some(op) = op else { continue };
+
+            let mut tape = self.tape.borrow_mut();
+            match op {
+                TapeOp::Addition(lhs, rhs) => {
+                    tape.nodes[lhs].grad = tape.nodes[lhs].grad + grad;
+                    tape.nodes[rhs].grad = tape.nodes[rhs].grad + grad;
+                }
+                TapeOp::Subtraction(lhs, rhs) => {
+                    tape.nodes[lhs].grad = tape.nodes[lhs].grad + grad;
+                    tape.nodes[rhs].grad = tape.nodes[rhs].grad - grad;
+                }
+                TapeOp::Multiplication(lhs, rhs) => {
+                    let lhs_data = tape.nodes[lhs].data;
+                    let rhs_data = tape.nodes[rhs].data;
+                    tape.nodes[lhs].grad = tape.nodes[lhs].grad + grad * rhs_data;
+                    tape.nodes[rhs].grad = tape.nodes[rhs].grad + grad * lhs_data;
+                }
+                TapeOp::Exponentiation(base, pow) => {
+                    if pow > 0 {
+                        let base_data = tape.nodes[base].data;
+                        tape.nodes[base].grad = tape.nodes[base].grad
+                            + grad * T::from(pow).unwrap() * base_data.powi(pow as i32 - 1);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<T: Float> std::ops::Add for TapeValue<T> {
+    type Output = TapeValue<T>;
+
+    fn add(self, other: TapeValue<T>) -> TapeValue<T> {
+        assert!(
+            Rc::ptr_eq(&self.tape, &other.tape),
+            "cannot combine TapeValues from different tapes"
+        );
+        let data = self.data() + other.data();
+        let idx = self
+            .tape
+            .borrow_mut()
+            .push(data, Some(TapeOp::Addition(self.idx, other.idx)));
+        TapeValue {
+            tape: self.tape,
+            idx,
+        }
+    }
+}
+
+impl<T: Float> Sub for TapeValue<T> {
+    type Output = TapeValue<T>;
+
+    fn sub(self, other: TapeValue<T>) -> TapeValue<T> {
+        assert!(
+            Rc::ptr_eq(&self.tape, &other.tape),
+            "cannot combine TapeValues from different tapes"
+        );
+        let data = self.data() - other.data();
+        let idx = self
+            .tape
+            .borrow_mut()
+            .push(data, Some(TapeOp::Subtraction(self.idx, other.idx)));
+        TapeValue {
+            tape: self.tape,
+            idx,
+        }
+    }
+}
+
+impl<T: Float> Mul for TapeValue<T> {
+    type Output = TapeValue<T>;
+
+    fn mul(self, other: TapeValue<T>) -> TapeValue<T> {
+        assert!(
+            Rc::ptr_eq(&self.tape, &other.tape),
+            "cannot combine TapeValues from different tapes"
+        );
+        let data = self.data() * other.data();
+        let idx = self
+            .tape
+            .borrow_mut()
+            .push(data, Some(TapeOp::Multiplication(self.idx, other.idx)));
+        TapeValue {
+            tape: self.tape,
+            idx,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_tape_add() {
+        let tape = Tape::new();
+        let a = TapeValue::from_val(&tape, 1.0);
+        let b = TapeValue::from_val(&tape, 2.0);
+        let c = a.clone() + b.clone();
+        c.backward();
+
+        assert_eq!(c.data(), 3.0);
+        assert_eq!(a.grad(), 1.0);
+        assert_eq!(b.grad(), 1.0);
+    }
+
+    #[test]
+    fn test_tape_mul() {
+        let tape = Tape::new();
+        let a = TapeValue::from_val(&tape, 11.0);
+        let b = TapeValue::from_val(&tape, 12.0);
+        let c = a.clone() * b.clone();
+        c.backward();
+
+        assert_eq!(c.data(), 132.0);
+        assert_eq!(a.grad(), 12.0);
+        assert_eq!(b.grad(), 11.0);
+    }
+
+    #[test]
+    fn test_tape_value_reuse_accumulates_grad() {
+        let tape = Tape::new();
+        let a = TapeValue::from_val(&tape, 1.0);
+        let b = a.clone() + a.clone();
+        b.backward();
+
+        assert_eq!(a.grad(), 2.0);
+        assert_eq!(b.grad(), 1.0);
+    }
+
+    #[test]
+    fn test_tape_powi() {
+        let tape = Tape::new();
+        let x = TapeValue::from_val(&tape, 3.0);
+        let y = TapeValue::from_val(&tape, 4.0) * x.clone().powi(5);
+        y.backward();
+
+        assert_eq!(y.data(), 972.0);
+        assert_eq!(x.grad(), 4.0 * 5.0 * x.data().powi(4));
+    }
+
+    #[test]
+    fn test_tape_deep_chain_does_not_overflow_stack() {
+        let tape = Tape::new();
+        let mut chain = TapeValue::from_val(&tape, 1.0);
+        for _ in 0..200_000 {
+            chain = chain + TapeValue::from_val(&tape, 1.0);
+        }
+        chain.backward();
+
+        assert_eq!(chain.data(), 200_001.0);
+        assert_eq!(chain.grad(), 1.0);
+    }
+}